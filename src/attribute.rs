@@ -0,0 +1,240 @@
+//! IPP attributes and attribute groups
+
+use std::io::{Read, Write};
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+
+use crate::{consts::tag::DelimiterTag, value::IppValue, IppError, ReadIppExt, Result};
+
+/// A single IPP attribute: a name paired with its value
+#[derive(Clone, Debug)]
+pub struct IppAttribute {
+    name: String,
+    value: IppValue,
+}
+
+impl IppAttribute {
+    /// Create a new attribute with the given name and value
+    pub fn new(name: &str, value: IppValue) -> IppAttribute {
+        IppAttribute {
+            name: name.to_string(),
+            value,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &IppValue {
+        &self.value
+    }
+
+    /// Fold in an additional value of a multi-valued attribute, turning this
+    /// attribute's value into a `ListOf` if it isn't one already
+    fn push_value(&mut self, value: IppValue) {
+        let first = std::mem::replace(&mut self.value, IppValue::ListOf(Vec::new()));
+        self.value = match first {
+            IppValue::ListOf(mut values) => {
+                values.push(value);
+                IppValue::ListOf(values)
+            }
+            first => IppValue::ListOf(vec![first, value]),
+        };
+    }
+
+    /// Write this attribute's wire-format record(s): one `tag/name-len/name/value-len/value`
+    /// record per value, with every value after the first using an empty name, per the IPP
+    /// encoding for multi-valued attributes
+    fn write(&self, writer: &mut dyn Write) -> Result<usize> {
+        match self.value {
+            IppValue::ListOf(ref values) if !values.is_empty() => {
+                let mut written = 0;
+                for (i, value) in values.iter().enumerate() {
+                    let name = if i == 0 { self.name.as_str() } else { "" };
+                    written += write_value_record(writer, name, value)?;
+                }
+                Ok(written)
+            }
+            ref value => write_value_record(writer, &self.name, value),
+        }
+    }
+}
+
+fn write_value_record(writer: &mut dyn Write, name: &str, value: &IppValue) -> Result<usize> {
+    writer.write_u8(value.tag())?;
+    writer.write_u16::<BigEndian>(name.len() as u16)?;
+    writer.write_all(name.as_bytes())?;
+
+    let mut encoded = Vec::new();
+    value.write_value(&mut encoded)?;
+    writer.write_u16::<BigEndian>(encoded.len() as u16)?;
+    writer.write_all(&encoded)?;
+
+    Ok(1 + 2 + name.len() + 2 + encoded.len())
+}
+
+/// A single group of attributes under one delimiter tag, e.g. the
+/// printer-attributes of one printer in a CUPS-Get-Printers response
+#[derive(Clone, Debug)]
+pub struct IppAttributeGroup {
+    tag: DelimiterTag,
+    attributes: Vec<IppAttribute>,
+}
+
+impl IppAttributeGroup {
+    pub fn tag(&self) -> DelimiterTag {
+        self.tag
+    }
+
+    pub fn attributes(&self) -> &[IppAttribute] {
+        &self.attributes
+    }
+}
+
+/// Attributes of an IPP request or response
+///
+/// Groups are kept in wire order rather than merged by tag: a response can
+/// legitimately repeat the same delimiter tag once per object, for example
+/// one `printer-attributes-tag` per printer in a CUPS-Get-Printers response,
+/// and each occurrence needs to stay a distinct group.
+#[derive(Clone, Debug, Default)]
+pub struct IppAttributeList {
+    groups: Vec<IppAttributeGroup>,
+}
+
+impl IppAttributeList {
+    /// Create an empty attribute list
+    pub fn new() -> IppAttributeList {
+        IppAttributeList::default()
+    }
+
+    /// Add an attribute to the given group, continuing the most recently
+    /// opened group if it already has the same tag, or opening a new one otherwise
+    pub fn set_attribute(&mut self, tag: DelimiterTag, attribute: IppAttribute) {
+        match self.groups.last_mut() {
+            Some(group) if group.tag == tag => group.attributes.push(attribute),
+            _ => self.groups.push(IppAttributeGroup {
+                tag,
+                attributes: vec![attribute],
+            }),
+        }
+    }
+
+    /// All groups with the given delimiter tag, in wire order
+    pub fn groups_of(&self, tag: DelimiterTag) -> impl Iterator<Item = &IppAttributeGroup> {
+        self.groups.iter().filter(move |group| group.tag == tag)
+    }
+
+    /// The first group with the given delimiter tag, if any
+    pub fn group(&self, tag: DelimiterTag) -> Option<&IppAttributeGroup> {
+        self.groups_of(tag).next()
+    }
+
+    /// Decode attribute groups from an incoming IPP message, stopping at end-of-attributes-tag
+    pub fn from_reader(reader: &mut dyn Read) -> Result<IppAttributeList> {
+        let mut list = IppAttributeList::new();
+
+        loop {
+            let tag = reader.read_u8()?;
+
+            if let Some(delimiter) = DelimiterTag::from_u8(tag) {
+                if delimiter == DelimiterTag::EndOfAttributes {
+                    break;
+                }
+                list.groups.push(IppAttributeGroup {
+                    tag: delimiter,
+                    attributes: Vec::new(),
+                });
+                continue;
+            }
+
+            let name_len = reader.read_u16::<BigEndian>()? as usize;
+            let name = reader.read_string(name_len)?;
+            let value_len = reader.read_u16::<BigEndian>()? as usize;
+            let value = IppValue::from_reader(tag, reader, value_len)?;
+
+            let group = match list.groups.last_mut() {
+                Some(group) => group,
+                None => {
+                    list.groups.push(IppAttributeGroup {
+                        tag: DelimiterTag::OperationAttributes,
+                        attributes: Vec::new(),
+                    });
+                    list.groups.last_mut().unwrap()
+                }
+            };
+
+            if name.is_empty() {
+                // additional value of a multi-valued attribute, e.g. printer-state-reasons
+                group
+                    .attributes
+                    .last_mut()
+                    .ok_or_else(|| IppError::AttributeError("additional value with no preceding attribute".to_string()))?
+                    .push_value(value);
+            } else {
+                group.attributes.push(IppAttribute::new(&name, value));
+            }
+        }
+
+        Ok(list)
+    }
+
+    /// Serialize all groups followed by the end-of-attributes-tag
+    pub fn write(&self, writer: &mut dyn Write) -> Result<usize> {
+        let mut written = 0;
+
+        for group in &self.groups {
+            writer.write_u8(group.tag as u8)?;
+            written += 1;
+
+            for attribute in &group.attributes {
+                written += attribute.write(writer)?;
+            }
+        }
+
+        writer.write_u8(DelimiterTag::EndOfAttributes as u8)?;
+        written += 1;
+
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_reader_keeps_repeated_tags_as_separate_groups() {
+        let mut wire = Vec::new();
+        wire.write_u8(DelimiterTag::PrinterAttributes as u8).unwrap();
+        write_value_record(&mut wire, "printer-name", &IppValue::Keyword("one".to_string())).unwrap();
+        wire.write_u8(DelimiterTag::PrinterAttributes as u8).unwrap();
+        write_value_record(&mut wire, "printer-name", &IppValue::Keyword("two".to_string())).unwrap();
+        wire.write_u8(DelimiterTag::EndOfAttributes as u8).unwrap();
+
+        let list = IppAttributeList::from_reader(&mut &wire[..]).unwrap();
+        let names: Vec<_> = list
+            .groups_of(DelimiterTag::PrinterAttributes)
+            .map(|group| group.attributes()[0].value().as_keyword().unwrap())
+            .collect();
+
+        assert_eq!(names, vec!["one", "two"]);
+    }
+
+    #[test]
+    fn from_reader_folds_additional_values_into_a_list_of() {
+        let mut wire = Vec::new();
+        wire.write_u8(DelimiterTag::PrinterAttributes as u8).unwrap();
+        write_value_record(&mut wire, "printer-state-reasons", &IppValue::Keyword("none".to_string())).unwrap();
+        write_value_record(&mut wire, "", &IppValue::Keyword("media-jam".to_string())).unwrap();
+        wire.write_u8(DelimiterTag::EndOfAttributes as u8).unwrap();
+
+        let list = IppAttributeList::from_reader(&mut &wire[..]).unwrap();
+        let group = list.group(DelimiterTag::PrinterAttributes).unwrap();
+
+        assert_eq!(group.attributes().len(), 1);
+        let reasons: Vec<_> = group.attributes()[0].value().iter().filter_map(IppValue::as_keyword).collect();
+        assert_eq!(reasons, vec!["none", "media-jam"]);
+    }
+}
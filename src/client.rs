@@ -0,0 +1,189 @@
+//!
+//! IPP client
+//!
+//! By default the crate builds in both a blocking client (feature `client`)
+//! and an async one (feature `async-client`, pulled in by the umbrella
+//! `async` feature), so applications can pick whichever transport suits them
+//! or use both side by side. Either way a request is built the same way,
+//! through `IppOperation::into_ipp_request`, and `IppRequestResponse`
+//! serialization is shared; only the HTTP transport differs.
+//!
+use std::time::Duration;
+
+use crate::{operation::IppOperation, request::IppRequestResponse, IppError, Result};
+
+/// Blocking IPP client
+#[cfg(feature = "client")]
+pub struct IppClient {
+    uri: String,
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "client")]
+impl IppClient {
+    /// Create IPP client for the given printer URI
+    pub fn new(uri: &str) -> IppClient {
+        IppClientBuilder::new(uri)
+            .build()
+            .expect("default client configuration cannot fail to build")
+    }
+
+    /// Send an IPP request and return the parsed response
+    pub fn send_request(&self, request: IppRequestResponse) -> Result<IppRequestResponse> {
+        let mut response = self
+            .client
+            .post(&self.uri)
+            .header(reqwest::header::CONTENT_TYPE, "application/ipp")
+            .body(reqwest::Body::new(request.into_reader()))
+            .send()?;
+
+        IppRequestResponse::from_reader(&mut response)
+    }
+
+    /// Build an IPP request from the operation and send it to the printer
+    pub fn send<T: IppOperation>(&self, operation: T) -> Result<IppRequestResponse> {
+        self.send_request(operation.into_ipp_request(&self.uri))
+    }
+}
+
+/// Builder for `IppClient`, allowing TLS, timeout and custom header configuration
+#[cfg(feature = "client")]
+pub struct IppClientBuilder {
+    uri: String,
+    ignore_tls_errors: bool,
+    request_timeout: Option<Duration>,
+    headers: reqwest::header::HeaderMap,
+    header_error: Option<IppError>,
+}
+
+#[cfg(feature = "client")]
+impl IppClientBuilder {
+    /// Create a new builder for the given printer URI
+    pub fn new(uri: &str) -> IppClientBuilder {
+        IppClientBuilder {
+            uri: uri.to_string(),
+            ignore_tls_errors: false,
+            request_timeout: None,
+            headers: reqwest::header::HeaderMap::new(),
+            header_error: None,
+        }
+    }
+
+    /// Disable TLS certificate verification, for self-signed printers
+    pub fn ignore_tls_errors(mut self, ignore_tls_errors: bool) -> IppClientBuilder {
+        self.ignore_tls_errors = ignore_tls_errors;
+        self
+    }
+
+    /// Bound how long a single HTTP request may take
+    pub fn request_timeout(mut self, request_timeout: Duration) -> IppClientBuilder {
+        self.request_timeout = Some(request_timeout);
+        self
+    }
+
+    /// Add a custom HTTP header to be sent with every request, for example an auth token
+    /// required by some print servers. May be called multiple times to add several headers.
+    pub fn http_header<K, V>(mut self, key: K, value: V) -> IppClientBuilder
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        if self.header_error.is_some() {
+            return self;
+        }
+
+        match (
+            reqwest::header::HeaderName::from_bytes(key.as_ref().as_bytes()),
+            reqwest::header::HeaderValue::from_str(value.as_ref()),
+        ) {
+            (Ok(name), Ok(value)) => {
+                self.headers.insert(name, value);
+            }
+            _ => {
+                self.header_error = Some(IppError::RequestError(format!(
+                    "invalid HTTP header: {}",
+                    key.as_ref()
+                )));
+            }
+        }
+        self
+    }
+
+    /// Build the configured `IppClient`
+    pub fn build(self) -> Result<IppClient> {
+        if let Some(error) = self.header_error {
+            return Err(error);
+        }
+
+        let mut builder = reqwest::Client::builder().default_headers(self.headers);
+
+        if let Some(timeout) = self.request_timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if self.ignore_tls_errors {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        Ok(IppClient {
+            uri: self.uri,
+            client: builder.build()?,
+        })
+    }
+}
+
+/// Async counterpart of `IppClient`, built on `reqwest`'s async client
+#[cfg(feature = "async-client")]
+pub struct IppAsyncClient {
+    uri: String,
+}
+
+#[cfg(feature = "async-client")]
+impl IppAsyncClient {
+    /// Create an async IPP client for the given printer URI
+    pub fn new(uri: &str) -> IppAsyncClient {
+        IppAsyncClient { uri: uri.to_string() }
+    }
+
+    /// Send an IPP request asynchronously and return the parsed response
+    pub fn send_request(
+        &self,
+        request: IppRequestResponse,
+    ) -> impl futures::Future<Item = IppRequestResponse, Error = IppError> {
+        use futures::{Future, Stream};
+        use std::io::Read;
+
+        let uri = self.uri.clone();
+
+        // `reqwest`'s async `Body` has no adapter for a blocking `Read`, so the
+        // serialized request is buffered into memory before being sent.
+        futures::future::lazy(move || {
+            let mut body = Vec::new();
+            request.into_reader().read_to_end(&mut body).map_err(IppError::from)?;
+            Ok(body)
+        })
+        .and_then(move |body| {
+            reqwest::r#async::Client::new()
+                .post(&uri)
+                .header(reqwest::header::CONTENT_TYPE, "application/ipp")
+                .body(reqwest::r#async::Body::from(body))
+                .send()
+                .map_err(IppError::from)
+                .and_then(|response| {
+                    response
+                        .into_body()
+                        .concat2()
+                        .map_err(IppError::from)
+                        .and_then(|body| IppRequestResponse::from_reader(&mut std::io::Cursor::new(body.to_vec())))
+                })
+        })
+    }
+
+    /// Build an IPP request from the operation and send it asynchronously
+    pub fn send<T: IppOperation>(
+        &self,
+        operation: T,
+    ) -> impl futures::Future<Item = IppRequestResponse, Error = IppError> {
+        self.send_request(operation.into_ipp_request(&self.uri))
+    }
+}
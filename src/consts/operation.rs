@@ -0,0 +1,13 @@
+//! IPP operation codes, as assigned by IANA
+
+/// IPP operation code, sent as part of the request header in place of the status code
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    PrintJob = 0x0002,
+    CreateJob = 0x0005,
+    SendDocument = 0x0006,
+    GetPrinterAttributes = 0x000B,
+
+    /// CUPS vendor extension: enumerate the printers configured on a CUPS server
+    CupsGetPrinters = 0x4002,
+}
@@ -0,0 +1,49 @@
+//! IPP status codes, as assigned by IANA
+
+use std::fmt;
+
+/// IPP status code, sent as part of the response header in place of the operation code
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StatusCode {
+    SuccessfulOk = 0x0000,
+    SuccessfulOkIgnoredOrSubstitutedAttributes = 0x0001,
+    SuccessfulOkConflictingAttributes = 0x0002,
+    ClientErrorBadRequest = 0x0400,
+    ClientErrorForbidden = 0x0401,
+    ClientErrorNotAuthenticated = 0x0402,
+    ClientErrorNotAuthorized = 0x0403,
+    ClientErrorNotPossible = 0x0404,
+    ClientErrorTimeout = 0x0405,
+    ClientErrorNotFound = 0x0406,
+    ServerErrorInternalError = 0x0500,
+    ServerErrorOperationNotSupported = 0x0501,
+    ServerErrorBusy = 0x0507,
+}
+
+impl StatusCode {
+    /// Convert a raw status code read off the wire into a `StatusCode`, if it is one
+    pub fn from_u16(code: u16) -> Option<StatusCode> {
+        match code {
+            0x0000 => Some(StatusCode::SuccessfulOk),
+            0x0001 => Some(StatusCode::SuccessfulOkIgnoredOrSubstitutedAttributes),
+            0x0002 => Some(StatusCode::SuccessfulOkConflictingAttributes),
+            0x0400 => Some(StatusCode::ClientErrorBadRequest),
+            0x0401 => Some(StatusCode::ClientErrorForbidden),
+            0x0402 => Some(StatusCode::ClientErrorNotAuthenticated),
+            0x0403 => Some(StatusCode::ClientErrorNotAuthorized),
+            0x0404 => Some(StatusCode::ClientErrorNotPossible),
+            0x0405 => Some(StatusCode::ClientErrorTimeout),
+            0x0406 => Some(StatusCode::ClientErrorNotFound),
+            0x0500 => Some(StatusCode::ServerErrorInternalError),
+            0x0501 => Some(StatusCode::ServerErrorOperationNotSupported),
+            0x0507 => Some(StatusCode::ServerErrorBusy),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,25 @@
+//! Delimiter tags that separate groups of attributes in an IPP message
+
+/// Delimiter tag, marking the start of a new group of attributes
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DelimiterTag {
+    OperationAttributes = 0x01,
+    JobAttributes = 0x02,
+    EndOfAttributes = 0x03,
+    PrinterAttributes = 0x04,
+    UnsupportedAttributes = 0x05,
+}
+
+impl DelimiterTag {
+    /// Convert a raw tag byte read off the wire into a `DelimiterTag`, if it is one
+    pub fn from_u8(tag: u8) -> Option<DelimiterTag> {
+        match tag {
+            0x01 => Some(DelimiterTag::OperationAttributes),
+            0x02 => Some(DelimiterTag::JobAttributes),
+            0x03 => Some(DelimiterTag::EndOfAttributes),
+            0x04 => Some(DelimiterTag::PrinterAttributes),
+            0x05 => Some(DelimiterTag::UnsupportedAttributes),
+            _ => None,
+        }
+    }
+}
@@ -20,12 +20,15 @@
 //!```rust
 //! // using operation API
 //! use ipp::{GetPrinterAttributes, IppClient};
+//! use ipp::util::printer_attributes;
 
 //! let operation = GetPrinterAttributes::new();
 //! let client = IppClient::new("http://localhost:631/printers/test-printer");
-//! if let Ok(attrs) = client.send(operation) {
-//!     for (_, v) in attrs.get_printer_attributes().unwrap() {
-//!         println!("{}: {}", v.name(), v.value());
+//! if let Ok(resp) = client.send(operation) {
+//!     if let Some(attrs) = printer_attributes(resp.attributes()) {
+//!         for attr in attrs {
+//!             println!("{}: {:?}", attr.name(), attr.value());
+//!         }
 //!     }
 //! }
 
@@ -36,8 +39,11 @@ extern crate clap;
 extern crate reqwest;
 extern crate url;
 extern crate num_traits;
-#[macro_use] extern crate enum_primitive_derive;
-#[macro_use] extern crate log;
+extern crate tempfile;
+extern crate enum_primitive_derive;
+extern crate log;
+#[cfg(feature = "async-client")]
+extern crate futures;
 
 use std::result;
 use std::fmt;
@@ -45,26 +51,23 @@ use std::io::{self, Read, Write};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 
 pub mod consts {
-    //! This module holds IPP constants such as attribute names, operations and tags
+    //! This module holds IPP constants such as operations, tags and status codes
     pub mod tag;
     pub mod statuscode;
     pub mod operation;
-    pub mod attribute;
 }
 
 pub mod value;
-pub mod parser;
 pub mod request;
 pub mod attribute;
 pub mod client;
 pub mod server;
 pub mod operation;
 pub mod util;
-pub mod ffi;
 
 pub use attribute::{IppAttribute, IppAttributeList};
 pub use client::IppClient;
-pub use operation::{IppOperation, PrintJob, GetPrinterAttributes, CreateJob, SendDocument};
+pub use operation::{IppOperation, PrintJob, GetPrinterAttributes, CreateJob, SendDocument, CupsGetPrinters};
 pub use request::IppRequestResponse;
 pub use value::IppValue;
 pub const IPP_VERSION: u16 = 0x0101;
@@ -80,19 +83,21 @@ pub enum IppError {
     AttributeError(String),
     StatusError(consts::statuscode::StatusCode),
     TagError(u8),
-    ParamError(clap::Error)
+    ParamError(clap::Error),
+    PrinterNotReady
 }
 
 impl IppError {
     pub fn as_exit_code(&self) -> i32 {
         match self {
-            &IppError::HttpError(_) => 2,
-            &IppError::IOError(_) => 3,
-            &IppError::RequestError(_) => 4,
-            &IppError::AttributeError(_) => 5,
-            &IppError::StatusError(_) => 6,
-            &IppError::TagError(_) => 7,
-            &IppError::ParamError(_) => 1
+            IppError::HttpError(_) => 2,
+            IppError::IOError(_) => 3,
+            IppError::RequestError(_) => 4,
+            IppError::AttributeError(_) => 5,
+            IppError::StatusError(_) => 6,
+            IppError::TagError(_) => 7,
+            IppError::ParamError(_) => 1,
+            IppError::PrinterNotReady => 8
         }
     }
 }
@@ -100,13 +105,14 @@ impl IppError {
 impl fmt::Display for IppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            &IppError::HttpError(ref e) => write!(f, "{}", e),
-            &IppError::IOError(ref e) => write!(f, "{}", e),
-            &IppError::RequestError(ref e) => write!(f, "IPP request error: {}", e),
-            &IppError::AttributeError(ref e) => write!(f, "IPP attribute error: {}", e),
-            &IppError::StatusError(ref e) => write!(f, "IPP status error: {}", e),
-            &IppError::TagError(ref e) => write!(f, "IPP tag error: {:0x}", e),
-            &IppError::ParamError(ref e) => write!(f, "IPP tag error: {}", e)
+            IppError::HttpError(e) => write!(f, "{}", e),
+            IppError::IOError(e) => write!(f, "{}", e),
+            IppError::RequestError(e) => write!(f, "IPP request error: {}", e),
+            IppError::AttributeError(e) => write!(f, "IPP attribute error: {}", e),
+            IppError::StatusError(e) => write!(f, "IPP status error: {}", e),
+            IppError::TagError(e) => write!(f, "IPP tag error: {:0x}", e),
+            IppError::ParamError(e) => write!(f, "IPP tag error: {}", e),
+            IppError::PrinterNotReady => write!(f, "printer is not ready to accept jobs")
         }
     }
 }
@@ -146,7 +152,7 @@ pub struct IppHeader {
 }
 
 impl IppHeader {
-    pub fn from_reader(reader: &mut Read) -> Result<IppHeader> {
+    pub fn from_reader(reader: &mut dyn Read) -> Result<IppHeader> {
         let retval = IppHeader::new(
             reader.read_u16::<BigEndian>()?,
             reader.read_u16::<BigEndian>()?,
@@ -159,7 +165,7 @@ impl IppHeader {
         IppHeader { version, operation_status: status, request_id }
     }
 
-    pub fn write(&self, writer: &mut Write) -> Result<usize> {
+    pub fn write(&self, writer: &mut dyn Write) -> Result<usize> {
         writer.write_u16::<BigEndian>(self.version)?;
         writer.write_u16::<BigEndian>(self.operation_status)?;
         writer.write_u32::<BigEndian>(self.request_id)?;
@@ -175,8 +181,7 @@ pub trait ReadIppExt: Read {
     }
 
     fn read_vec(&mut self, len: usize) -> std::io::Result<Vec<u8>> {
-        let mut namebuf: Vec<u8> = Vec::with_capacity(len);
-        unsafe { namebuf.set_len(len) };
+        let mut namebuf: Vec<u8> = vec![0; len];
 
         self.read_exact(&mut namebuf)?;
 
@@ -0,0 +1,236 @@
+//!
+//! High-level IPP operation abstractions
+//!
+use crate::{
+    attribute::IppAttribute,
+    consts::{operation::Operation, tag::DelimiterTag},
+    request::{IppReadStream, IppRequestResponse},
+    value::IppValue,
+};
+
+/// Trait which represents a single IPP operation
+pub trait IppOperation {
+    /// Convert this operation to an IPP request which is ready for sending
+    fn into_ipp_request(self, uri: &str) -> IppRequestResponse;
+}
+
+/// IPP operation Print-Job
+pub struct PrintJob {
+    stream: IppReadStream,
+    user_name: Option<String>,
+    job_name: Option<String>,
+    attributes: Vec<IppAttribute>,
+}
+
+impl PrintJob {
+    /// Create Print-Job operation
+    ///
+    /// * `stream` - `IppReadStream`<br/>
+    /// * `user_name` - name of the user (requesting-user-name)<br/>
+    /// * `job_name` - optional job name (job-name)<br/>
+    pub fn new<U, N>(stream: IppReadStream, user_name: Option<U>, job_name: Option<N>) -> PrintJob
+    where
+        U: AsRef<str>,
+        N: AsRef<str>,
+    {
+        PrintJob {
+            stream,
+            user_name: user_name.map(|v| v.as_ref().to_string()),
+            job_name: job_name.map(|v| v.as_ref().to_string()),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Set extra job attribute for this operation, for example `colormodel=grayscale`
+    pub fn add_attribute(&mut self, attribute: IppAttribute) {
+        self.attributes.push(attribute);
+    }
+}
+
+impl IppOperation for PrintJob {
+    fn into_ipp_request(self, uri: &str) -> IppRequestResponse {
+        let mut retval = IppRequestResponse::new(Operation::PrintJob, uri);
+
+        if let Some(ref user_name) = self.user_name {
+            retval.set_attribute(
+                DelimiterTag::OperationAttributes,
+                IppAttribute::new("requesting-user-name", IppValue::NameWithoutLanguage(user_name.clone())),
+            );
+        }
+
+        if let Some(ref job_name) = self.job_name {
+            retval.set_attribute(
+                DelimiterTag::OperationAttributes,
+                IppAttribute::new("job-name", IppValue::NameWithoutLanguage(job_name.clone())),
+            )
+        }
+
+        for attr in self.attributes {
+            retval.set_attribute(DelimiterTag::JobAttributes, attr);
+        }
+        retval.set_payload(self.stream);
+        retval
+    }
+}
+
+/// IPP operation Get-Printer-Attributes
+#[derive(Default)]
+pub struct GetPrinterAttributes {
+    attributes: Vec<String>,
+}
+
+impl GetPrinterAttributes {
+    /// Create Get-Printer-Attributes operation
+    pub fn new() -> GetPrinterAttributes {
+        GetPrinterAttributes::default()
+    }
+
+    /// Set attributes to request from the printer
+    pub fn with_attributes<T>(attributes: &[T]) -> GetPrinterAttributes
+    where
+        T: AsRef<str>,
+    {
+        GetPrinterAttributes {
+            attributes: attributes.iter().map(|a| a.as_ref().to_string()).collect(),
+        }
+    }
+}
+
+impl IppOperation for GetPrinterAttributes {
+    fn into_ipp_request(self, uri: &str) -> IppRequestResponse {
+        let mut retval = IppRequestResponse::new(Operation::GetPrinterAttributes, uri);
+
+        if !self.attributes.is_empty() {
+            let vals: Vec<IppValue> = self.attributes.into_iter().map(IppValue::Keyword).collect();
+            retval.set_attribute(
+                DelimiterTag::OperationAttributes,
+                IppAttribute::new("requested-attributes", IppValue::ListOf(vals)),
+            );
+        }
+
+        retval
+    }
+}
+
+/// IPP operation Create-Job
+pub struct CreateJob {
+    job_name: Option<String>,
+    attributes: Vec<IppAttribute>,
+}
+
+impl CreateJob {
+    /// Create Create-Job operation
+    ///
+    /// * `job_name` - optional job name (job-name)<br/>
+    pub fn new<T>(job_name: Option<T>) -> CreateJob
+    where
+        T: AsRef<str>,
+    {
+        CreateJob {
+            job_name: job_name.map(|v| v.as_ref().to_string()),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Set extra job attribute for this operation, for example `colormodel=grayscale`
+    pub fn add_attribute(&mut self, attribute: IppAttribute) {
+        self.attributes.push(attribute);
+    }
+}
+
+impl IppOperation for CreateJob {
+    fn into_ipp_request(self, uri: &str) -> IppRequestResponse {
+        let mut retval = IppRequestResponse::new(Operation::CreateJob, uri);
+
+        if let Some(ref job_name) = self.job_name {
+            retval.set_attribute(
+                DelimiterTag::OperationAttributes,
+                IppAttribute::new("job-name", IppValue::NameWithoutLanguage(job_name.clone())),
+            )
+        }
+
+        for attr in self.attributes {
+            retval.set_attribute(DelimiterTag::JobAttributes, attr);
+        }
+        retval
+    }
+}
+
+/// IPP operation Send-Document
+pub struct SendDocument {
+    job_id: i32,
+    stream: IppReadStream,
+    user_name: Option<String>,
+    last: bool,
+}
+
+impl SendDocument {
+    /// Create Send-Document operation
+    ///
+    /// * `job_id` - job ID returned by Create-Job operation<br/>
+    /// * `stream` - `IppReadStream`<br/>
+    /// * `user_name` - name of the user (requesting-user-name)<br/>
+    /// * `last` - whether this document is the last one<br/>
+    pub fn new<S>(job_id: i32, stream: IppReadStream, user_name: Option<S>, last: bool) -> SendDocument
+    where
+        S: AsRef<str>,
+    {
+        SendDocument {
+            job_id,
+            stream,
+            user_name: user_name.map(|v| v.as_ref().to_string()),
+            last,
+        }
+    }
+}
+
+impl IppOperation for SendDocument {
+    fn into_ipp_request(self, uri: &str) -> IppRequestResponse {
+        let mut retval = IppRequestResponse::new(Operation::SendDocument, uri);
+
+        retval.set_attribute(
+            DelimiterTag::OperationAttributes,
+            IppAttribute::new("job-id", IppValue::Integer(self.job_id)),
+        );
+
+        if let Some(user_name) = self.user_name {
+            retval.set_attribute(
+                DelimiterTag::OperationAttributes,
+                IppAttribute::new("requesting-user-name", IppValue::NameWithoutLanguage(user_name)),
+            );
+        }
+
+        retval.set_attribute(
+            DelimiterTag::OperationAttributes,
+            IppAttribute::new("last-document", IppValue::Boolean(self.last)),
+        );
+
+        retval.set_payload(self.stream);
+
+        retval
+    }
+}
+
+/// CUPS vendor operation CUPS-Get-Printers
+pub struct CupsGetPrinters;
+
+impl CupsGetPrinters {
+    /// Create CUPS-Get-Printers operation
+    pub fn new() -> CupsGetPrinters {
+        CupsGetPrinters
+    }
+}
+
+impl Default for CupsGetPrinters {
+    fn default() -> CupsGetPrinters {
+        CupsGetPrinters::new()
+    }
+}
+
+impl IppOperation for CupsGetPrinters {
+    fn into_ipp_request(self, _uri: &str) -> IppRequestResponse {
+        // CUPS-Get-Printers targets the server itself rather than a specific
+        // printer or job, so no target object attributes are added here.
+        IppRequestResponse::new_without_target(Operation::CupsGetPrinters)
+    }
+}
@@ -0,0 +1,121 @@
+//!
+//! IPP request and response container
+//!
+use std::io::{Cursor, Read};
+
+use tempfile::NamedTempFile;
+
+use crate::{
+    attribute::{IppAttribute, IppAttributeList},
+    consts::{operation::Operation, tag::DelimiterTag},
+    value::IppValue,
+    IppHeader, Result, IPP_VERSION,
+};
+
+/// Boxed stream supplying an outgoing document payload
+pub type IppReadStream = Box<dyn Read + Send>;
+
+/// Where the document bytes carried by an `IppRequestResponse` come from
+pub enum PayloadKind {
+    /// A stream supplied by the caller, to be sent as a job's document
+    JobSource(IppReadStream),
+    /// Document bytes received from the network, spooled to a temporary file
+    /// instead of being buffered in memory
+    ReceivedData(NamedTempFile),
+}
+
+/// IPP request or response: a header, one or more attribute groups and an
+/// optional document payload
+pub struct IppRequestResponse {
+    header: IppHeader,
+    attributes: IppAttributeList,
+    payload: Option<PayloadKind>,
+}
+
+impl IppRequestResponse {
+    /// Create a new outgoing request for the given operation and target URI
+    pub fn new(operation: Operation, uri: &str) -> IppRequestResponse {
+        let mut retval = IppRequestResponse::new_without_target(operation);
+        retval.attributes.set_attribute(
+            DelimiterTag::OperationAttributes,
+            IppAttribute::new("printer-uri", IppValue::Uri(uri.to_string())),
+        );
+        retval
+    }
+
+    /// Create a new outgoing request for an operation that targets the server
+    /// itself rather than a specific printer or job, so no `printer-uri`
+    /// operation attribute is added
+    pub fn new_without_target(operation: Operation) -> IppRequestResponse {
+        IppRequestResponse {
+            header: IppHeader::new(IPP_VERSION, operation as u16, 1),
+            attributes: IppAttributeList::new(),
+            payload: None,
+        }
+    }
+
+    /// This request's or response's header
+    pub fn header(&self) -> &IppHeader {
+        &self.header
+    }
+
+    /// This request's or response's attributes
+    pub fn attributes(&self) -> &IppAttributeList {
+        &self.attributes
+    }
+
+    /// Set an attribute in the given delimiter group
+    pub fn set_attribute(&mut self, group: DelimiterTag, attribute: IppAttribute) {
+        self.attributes.set_attribute(group, attribute);
+    }
+
+    /// Attach an outgoing document stream to this request
+    pub fn set_payload(&mut self, stream: IppReadStream) {
+        self.payload = Some(PayloadKind::JobSource(stream));
+    }
+
+    /// Replace this request's payload, used by the server-side parser to
+    /// attach the temporary file a received document was spooled to
+    pub(crate) fn set_payload_kind(&mut self, kind: PayloadKind) {
+        self.payload = Some(kind);
+    }
+
+    /// The temporary file holding a document received by a server, if any
+    pub fn received_data(&self) -> Option<&NamedTempFile> {
+        match self.payload {
+            Some(PayloadKind::ReceivedData(ref file)) => Some(file),
+            _ => None,
+        }
+    }
+
+    /// Serialize this request or response for sending over the wire,
+    /// followed by the outgoing document payload, if any
+    pub fn into_reader(self) -> Box<dyn Read + Send> {
+        let mut buf = Vec::new();
+        self.header
+            .write(&mut buf)
+            .expect("writing an IPP header into an in-memory buffer cannot fail");
+        self.attributes
+            .write(&mut buf)
+            .expect("writing IPP attributes into an in-memory buffer cannot fail");
+
+        let header_and_attributes = Cursor::new(buf);
+
+        match self.payload {
+            Some(PayloadKind::JobSource(stream)) => Box::new(header_and_attributes.chain(stream)),
+            _ => Box::new(header_and_attributes),
+        }
+    }
+
+    /// Parse an IPP response read back from a client request
+    pub fn from_reader(reader: &mut dyn Read) -> Result<IppRequestResponse> {
+        let header = IppHeader::from_reader(reader)?;
+        let attributes = IppAttributeList::from_reader(reader)?;
+
+        Ok(IppRequestResponse {
+            header,
+            attributes,
+            payload: None,
+        })
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! Server-side IPP request parsing
+//!
+use std::io::{Read, Write};
+
+use tempfile::NamedTempFile;
+
+use crate::request::{IppRequestResponse, PayloadKind};
+use crate::Result;
+
+impl IppRequestResponse {
+    /// Parse an IPP request received by a server.
+    ///
+    /// The header and attribute groups are decoded as usual, but the
+    /// trailing document bytes (if any) are streamed straight into a
+    /// `NamedTempFile` rather than being buffered in memory, so that large
+    /// print jobs don't blow up server memory use.
+    pub fn from_request_reader(reader: &mut dyn Read) -> Result<IppRequestResponse> {
+        let mut retval = IppRequestResponse::from_reader(reader)?;
+
+        let mut tmp_file = NamedTempFile::new()?;
+        let copied = std::io::copy(reader, &mut tmp_file)?;
+
+        if copied > 0 {
+            tmp_file.flush()?;
+            retval.set_payload_kind(PayloadKind::ReceivedData(tmp_file));
+        }
+
+        Ok(retval)
+    }
+}
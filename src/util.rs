@@ -0,0 +1,104 @@
+//!
+//! Helpers for common print workflows
+//!
+use crate::{
+    attribute::{IppAttribute, IppAttributeList},
+    consts::tag::DelimiterTag,
+    IppError, Result,
+};
+#[cfg(test)]
+use crate::value::IppValue;
+
+const PRINTER_STATE: &str = "printer-state";
+const PRINTER_STATE_REASONS: &str = "printer-state-reasons";
+
+/// IPP printer-state value meaning the printer is stopped and cannot accept jobs
+const PRINTER_STATE_STOPPED: i32 = 5;
+
+/// Find the printer-attributes group in a Get-Printer-Attributes response
+pub fn printer_attributes(attributes: &IppAttributeList) -> Option<&[IppAttribute]> {
+    attributes.group(DelimiterTag::PrinterAttributes).map(|group| group.attributes())
+}
+
+/// Inspect `printer-state` and `printer-state-reasons` from a
+/// Get-Printer-Attributes response to determine whether the printer can
+/// accept jobs right now.
+pub fn is_printer_ready(attributes: &IppAttributeList) -> Result<bool> {
+    let group = printer_attributes(attributes).ok_or_else(|| IppError::PrinterNotReady)?;
+
+    let state = group
+        .iter()
+        .find(|attr| attr.name() == PRINTER_STATE)
+        .and_then(|attr| attr.value().as_enum())
+        .ok_or_else(|| IppError::PrinterNotReady)?;
+
+    if *state == PRINTER_STATE_STOPPED {
+        return Ok(false);
+    }
+
+    let has_error_reason = group
+        .iter()
+        .find(|attr| attr.name() == PRINTER_STATE_REASONS)
+        .map(|attr| attr.value().iter().any(|v| v.as_keyword().map(|r| r.ends_with("-error")).unwrap_or(false)))
+        .unwrap_or(false);
+
+    Ok(!has_error_reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRINTER_STATE_IDLE: i32 = 3;
+
+    fn attributes_with(extra: Vec<IppAttribute>) -> IppAttributeList {
+        let mut attributes = IppAttributeList::new();
+        for attr in extra {
+            attributes.set_attribute(DelimiterTag::PrinterAttributes, attr);
+        }
+        attributes
+    }
+
+    #[test]
+    fn idle_printer_with_no_reasons_is_ready() {
+        let attributes = attributes_with(vec![
+            IppAttribute::new(PRINTER_STATE, IppValue::Enum(PRINTER_STATE_IDLE)),
+            IppAttribute::new(PRINTER_STATE_REASONS, IppValue::Keyword("none".to_string())),
+        ]);
+
+        assert!(is_printer_ready(&attributes).unwrap());
+    }
+
+    #[test]
+    fn stopped_printer_is_not_ready() {
+        let attributes = attributes_with(vec![IppAttribute::new(
+            PRINTER_STATE,
+            IppValue::Enum(PRINTER_STATE_STOPPED),
+        )]);
+
+        assert!(!is_printer_ready(&attributes).unwrap());
+    }
+
+    #[test]
+    fn printer_with_an_error_reason_is_not_ready() {
+        let attributes = attributes_with(vec![
+            IppAttribute::new(PRINTER_STATE, IppValue::Enum(PRINTER_STATE_IDLE)),
+            IppAttribute::new(
+                PRINTER_STATE_REASONS,
+                IppValue::ListOf(vec![
+                    IppValue::Keyword("none".to_string()),
+                    IppValue::Keyword("media-jam-error".to_string()),
+                ]),
+            ),
+        ]);
+
+        assert!(!is_printer_ready(&attributes).unwrap());
+    }
+
+    #[test]
+    fn missing_printer_attributes_group_is_an_error() {
+        let attributes = IppAttributeList::new();
+
+        assert!(is_printer_ready(&attributes).is_err());
+    }
+}
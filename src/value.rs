@@ -0,0 +1,185 @@
+//! IPP attribute value representation
+
+use std::io::{Read, Write};
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+
+use crate::{IppError, ReadIppExt, Result};
+
+/// A single IPP attribute value
+#[derive(Clone, Debug, PartialEq)]
+pub enum IppValue {
+    Integer(i32),
+    Boolean(bool),
+    Enum(i32),
+    Keyword(String),
+    NameWithoutLanguage(String),
+    TextWithoutLanguage(String),
+    Uri(String),
+    ListOf(Vec<IppValue>),
+}
+
+impl IppValue {
+    /// Decode a single value off the wire, given its value tag and encoded length
+    pub fn from_reader(tag: u8, reader: &mut dyn Read, len: usize) -> Result<IppValue> {
+        match tag {
+            0x21 => Ok(IppValue::Integer(reader.read_i32::<BigEndian>()?)),
+            0x22 => Ok(IppValue::Boolean(reader.read_u8()? != 0)),
+            0x23 => Ok(IppValue::Enum(reader.read_i32::<BigEndian>()?)),
+            0x41 => Ok(IppValue::TextWithoutLanguage(reader.read_string(len)?)),
+            0x42 => Ok(IppValue::NameWithoutLanguage(reader.read_string(len)?)),
+            0x44 => Ok(IppValue::Keyword(reader.read_string(len)?)),
+            0x45 => Ok(IppValue::Uri(reader.read_string(len)?)),
+            _ => Err(IppError::TagError(tag)),
+        }
+    }
+
+    /// Get this value as an integer, if it holds one
+    pub fn as_integer(&self) -> Option<&i32> {
+        match self {
+            IppValue::Integer(ref i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Get this value as an enum value, if it holds one
+    pub fn as_enum(&self) -> Option<&i32> {
+        match self {
+            IppValue::Enum(ref i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Get this value as a boolean, if it holds one
+    pub fn as_boolean(&self) -> Option<&bool> {
+        match self {
+            IppValue::Boolean(ref b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Get this value as a keyword, if it holds one
+    pub fn as_keyword(&self) -> Option<&str> {
+        match self {
+            IppValue::Keyword(ref s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Get this value as a name, text or URI string, if it holds one
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            IppValue::NameWithoutLanguage(ref s)
+            | IppValue::TextWithoutLanguage(ref s)
+            | IppValue::Uri(ref s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The wire value-tag for this value, used when serializing it
+    pub(crate) fn tag(&self) -> u8 {
+        match self {
+            IppValue::Integer(_) => 0x21,
+            IppValue::Boolean(_) => 0x22,
+            IppValue::Enum(_) => 0x23,
+            IppValue::TextWithoutLanguage(_) => 0x41,
+            IppValue::NameWithoutLanguage(_) => 0x42,
+            IppValue::Keyword(_) => 0x44,
+            IppValue::Uri(_) => 0x45,
+            // a bare ListOf is only ever written one element at a time by
+            // `IppAttribute::write`; fall back to the first element's tag
+            IppValue::ListOf(ref values) => values.first().map(IppValue::tag).unwrap_or(0x44),
+        }
+    }
+
+    /// Write just this value's encoded bytes, without its tag, name or length prefix
+    pub(crate) fn write_value(&self, writer: &mut dyn Write) -> Result<()> {
+        match self {
+            IppValue::Integer(i) | IppValue::Enum(i) => writer.write_i32::<BigEndian>(*i)?,
+            IppValue::Boolean(b) => writer.write_u8(*b as u8)?,
+            IppValue::TextWithoutLanguage(s)
+            | IppValue::NameWithoutLanguage(s)
+            | IppValue::Keyword(s)
+            | IppValue::Uri(s) => writer.write_all(s.as_bytes())?,
+            IppValue::ListOf(values) => {
+                if let Some(first) = values.first() {
+                    first.write_value(writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the values held by an `IppValue`: the inner values for
+/// `ListOf`, or the value itself for any other (scalar) variant
+pub struct IppValueIterator<'a> {
+    value: &'a IppValue,
+    index: usize,
+}
+
+impl<'a> Iterator for IppValueIterator<'a> {
+    type Item = &'a IppValue;
+
+    fn next(&mut self) -> Option<&'a IppValue> {
+        let item = match self.value {
+            IppValue::ListOf(ref values) => values.get(self.index),
+            _ if self.index == 0 => Some(self.value),
+            _ => None,
+        };
+        self.index += 1;
+        item
+    }
+}
+
+impl<'a> IntoIterator for &'a IppValue {
+    type Item = &'a IppValue;
+    type IntoIter = IppValueIterator<'a>;
+
+    fn into_iter(self) -> IppValueIterator<'a> {
+        IppValueIterator { value: self, index: 0 }
+    }
+}
+
+impl IppValue {
+    /// Iterate over the values held by this value: the inner values for
+    /// `ListOf`, or this value itself for any other (scalar) variant
+    pub fn iter(&self) -> IppValueIterator<'_> {
+        self.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accessors_match_their_variant() {
+        assert_eq!(IppValue::Integer(42).as_integer(), Some(&42));
+        assert_eq!(IppValue::Enum(3).as_enum(), Some(&3));
+        assert_eq!(IppValue::Boolean(true).as_boolean(), Some(&true));
+        assert_eq!(IppValue::Keyword("idle".to_string()).as_keyword(), Some("idle"));
+        assert_eq!(IppValue::NameWithoutLanguage("foo".to_string()).as_string(), Some("foo"));
+    }
+
+    #[test]
+    fn accessors_return_none_for_other_variants() {
+        assert_eq!(IppValue::Boolean(true).as_integer(), None);
+        assert_eq!(IppValue::Integer(1).as_keyword(), None);
+        assert_eq!(IppValue::Keyword("x".to_string()).as_string(), None);
+    }
+
+    #[test]
+    fn iter_over_scalar_yields_itself_once() {
+        let value = IppValue::Keyword("idle".to_string());
+        let values: Vec<_> = value.iter().collect();
+        assert_eq!(values, vec![&value]);
+    }
+
+    #[test]
+    fn iter_over_list_of_yields_each_element() {
+        let value = IppValue::ListOf(vec![IppValue::Keyword("none".to_string()), IppValue::Keyword("media-jam".to_string())]);
+        let keywords: Vec<_> = value.iter().filter_map(IppValue::as_keyword).collect();
+        assert_eq!(keywords, vec!["none", "media-jam"]);
+    }
+}